@@ -0,0 +1,140 @@
+//! Bitcoin CompactSize ("VarInt") interop, an alternative wire format alongside the
+//! crate's own LEB128 encoding, for talking to consensus-encoded protocols directly.
+use crate::Uint;
+use core2::io::{Error, ErrorKind, Read, Write};
+
+impl Uint {
+    /// Write `self` using the Bitcoin CompactSize format: values below `0xFD` are a single
+    /// byte; otherwise a marker byte (`0xFD`, `0xFE`, or `0xFF`) is followed by a
+    /// little-endian `u16`, `u32`, or `u64`, using the smallest marker that fits.
+    ///
+    /// Returns an error if `self` does not fit in a `u64`, since CompactSize has no wider
+    /// form.
+    ///
+    /// ```
+    /// use uint_zigzag::Uint;
+    ///
+    /// let mut buffer = Vec::new();
+    /// Uint::from(255u32).to_compact_size(&mut buffer).unwrap();
+    /// assert_eq!(buffer, vec![0xFD, 0xFF, 0x00]);
+    /// ```
+    pub fn to_compact_size<W: Write>(&self, w: &mut W) -> Result<usize, Error> {
+        if self.0 < 0xFD {
+            return w.write(&[self.0 as u8]);
+        }
+
+        if self.0 <= u16::MAX as u128 {
+            let mut written = w.write(&[0xFDu8])?;
+            written += w.write(&(self.0 as u16).to_le_bytes())?;
+            return Ok(written);
+        }
+
+        if self.0 <= u32::MAX as u128 {
+            let mut written = w.write(&[0xFEu8])?;
+            written += w.write(&(self.0 as u32).to_le_bytes())?;
+            return Ok(written);
+        }
+
+        if self.0 <= u64::MAX as u128 {
+            let mut written = w.write(&[0xFFu8])?;
+            written += w.write(&(self.0 as u64).to_le_bytes())?;
+            return Ok(written);
+        }
+
+        Err(Error::new(
+            ErrorKind::InvalidData,
+            "value does not fit in CompactSize's 64-bit range",
+        ))
+    }
+
+    /// Read a `Uint` encoded as a Bitcoin CompactSize value, rejecting non-canonical
+    /// encodings (a value small enough for a narrower marker encoded with a wider one),
+    /// matching consensus rules.
+    pub fn from_compact_size<R: Read>(r: &mut R) -> Result<Self, Error> {
+        let mut marker = [0u8; 1];
+        r.read_exact(&mut marker)?;
+
+        match marker[0] {
+            b @ 0..=0xFC => Ok(Self(b as u128)),
+            0xFD => {
+                let mut buf = [0u8; 2];
+                r.read_exact(&mut buf)?;
+                let value = u16::from_le_bytes(buf);
+                if (value as u128) < 0xFD {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "non-canonical CompactSize encoding",
+                    ));
+                }
+                Ok(Self(value as u128))
+            }
+            0xFE => {
+                let mut buf = [0u8; 4];
+                r.read_exact(&mut buf)?;
+                let value = u32::from_le_bytes(buf);
+                if value as u128 <= u16::MAX as u128 {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "non-canonical CompactSize encoding",
+                    ));
+                }
+                Ok(Self(value as u128))
+            }
+            0xFF => {
+                let mut buf = [0u8; 8];
+                r.read_exact(&mut buf)?;
+                let value = u64::from_le_bytes(buf);
+                if value as u128 <= u32::MAX as u128 {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "non-canonical CompactSize encoding",
+                    ));
+                }
+                Ok(Self(value as u128))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn compact_size_round_trips_each_marker_range() {
+    for value in [0u128, 0xFC, 0xFD, u16::MAX as u128, u32::MAX as u128, u64::MAX as u128] {
+        let u = Uint(value);
+        let mut buffer = std::vec::Vec::new();
+        u.to_compact_size(&mut buffer).unwrap();
+
+        let mut cursor = buffer.as_slice();
+        let back = Uint::from_compact_size(&mut cursor).unwrap();
+        assert_eq!(back, u);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn compact_size_picks_smallest_marker() {
+    assert_eq!(encoded_len(0xFCu128), 1);
+    assert_eq!(encoded_len(0xFDu128), 3);
+    assert_eq!(encoded_len(u16::MAX as u128), 3);
+    assert_eq!(encoded_len(u16::MAX as u128 + 1), 5);
+    assert_eq!(encoded_len(u32::MAX as u128), 5);
+    assert_eq!(encoded_len(u32::MAX as u128 + 1), 9);
+
+    fn encoded_len(value: u128) -> usize {
+        let mut buffer = std::vec::Vec::new();
+        Uint(value).to_compact_size(&mut buffer).unwrap();
+        buffer.len()
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn compact_size_rejects_non_canonical_and_oversized_values() {
+    let non_canonical = [0xFDu8, 0x01, 0x00];
+    let mut cursor = &non_canonical[..];
+    assert!(Uint::from_compact_size(&mut cursor).is_err());
+
+    let oversized = Uint(u128::MAX);
+    let mut buffer = std::vec::Vec::new();
+    assert!(oversized.to_compact_size(&mut buffer).is_err());
+}
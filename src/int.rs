@@ -0,0 +1,245 @@
+//! Int is a convenience wrapper for zig-zag encoding signed integers to byte sequences.
+//!
+//! Unlike casting a negative number straight into `Uint`, `Int` maps signed values onto
+//! the unsigned line first (`0, -1, 1, -2, 2, ...`) so small-magnitude negatives stay just
+//! as compact as small-magnitude positives.
+use crate::Uint;
+use core::fmt::{self, Display, Formatter};
+use core2::io::{Error, ErrorKind, Read, Write};
+
+#[cfg(feature = "serde")]
+use ::serde::{
+    de::{Error as DError, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "std")]
+use std::{boxed::Box, vec::Vec};
+
+/// Int implements zig-zag encoding to represent signed integers as binary sequences
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Int(pub i128);
+
+impl Display for Int {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+macro_rules! impl_from {
+    ($($tt:ty),+) => {
+        $(
+        impl From<$tt> for Int {
+            fn from(v: $tt) -> Self {
+                Int(v as i128)
+            }
+        }
+
+        impl From<Int> for $tt {
+            fn from(v: Int) -> $tt {
+                v.0 as $tt
+            }
+        }
+        )+
+    };
+}
+
+impl From<i128> for Int {
+    fn from(v: i128) -> Self {
+        Self(v)
+    }
+}
+
+impl From<Int> for i128 {
+    fn from(v: Int) -> Self {
+        v.0
+    }
+}
+
+impl_from!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl TryFrom<&[u8]> for Int {
+    type Error = &'static str;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let z = Uint::try_from(value)?.0;
+        let n = (z >> 1) as i128 ^ -((z & 1) as i128);
+        Ok(Self(n))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl TryFrom<&Vec<u8>> for Int {
+    type Error = &'static str;
+
+    fn try_from(value: &Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl TryFrom<&Box<Vec<u8>>> for Int {
+    type Error = &'static str;
+
+    fn try_from(value: &Box<Vec<u8>>) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[cfg(feature = "serde")]
+impl Serialize for Int {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut buffer = [0u8; Self::MAX_BYTES];
+        let length = self.to_bytes_with_length(&mut buffer);
+        serializer.serialize_bytes(&buffer[..length])
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Int {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct IntVisitor;
+
+        impl<'de> Visitor<'de> for IntVisitor {
+            type Value = Int;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "a byte sequence")
+            }
+
+            fn visit_bytes<E: DError>(self, v: &[u8]) -> Result<Self::Value, E> {
+                match Int::try_from(v) {
+                    Err(_) => Err(DError::invalid_length(v.len(), &self)),
+                    Ok(i) => Ok(i),
+                }
+            }
+        }
+
+        deserializer.deserialize_bytes(IntVisitor)
+    }
+}
+
+impl Int {
+    /// The maximum number of bytes an int will consume
+    pub const MAX_BYTES: usize = Uint::MAX_BYTES;
+
+    /// Peek returns the number of bytes that would be read
+    /// or None if no Int can be read
+    ///
+    /// ```
+    /// use uint_zigzag::Int;
+    ///
+    /// let buffer = [0x34u8];
+    ///
+    /// let out = Int::peek(&buffer);
+    ///
+    /// assert!(out.is_some());
+    ///
+    /// let out = Int::peek(&[]);
+    ///
+    /// assert!(out.is_none());
+    /// ```
+    pub fn peek(value: &[u8]) -> Option<usize> {
+        Uint::peek(value)
+    }
+
+    /// Zig-zag encoding, any length from 1 to MAX_BYTES into buffer
+    /// buffer must be big enough to hold the result
+    ///
+    /// ```
+    /// use uint_zigzag::Int;
+    ///
+    /// let mut buffer = [0u8, 0u8];
+    /// let u = Int::from(-1);
+    /// u.to_bytes(&mut buffer);
+    ///
+    /// assert_eq!(buffer, [0x01u8, 0u8]);
+    /// ```
+    pub fn to_bytes<M: AsMut<[u8]>>(&self, mut buffer: M) {
+        self.to_bytes_with_length(buffer.as_mut());
+    }
+
+    /// Same as `to_bytes` except it returns how many bytes were actually used
+    pub fn to_bytes_with_length(self, buffer: &mut [u8]) -> usize {
+        let zig = ((self.0 << 1) ^ (self.0 >> 127)) as u128;
+        Uint(zig).to_bytes_with_length(buffer)
+    }
+
+    /// Zig-zag encoding, any length from 1 to MAX_BYTES
+    ///
+    /// ```
+    /// use uint_zigzag::Int;
+    ///
+    /// let i = Int(-1);
+    /// let out = i.to_vec();
+    ///
+    /// assert_eq!(out.as_slice(), &[1]);
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut output = [0u8; Self::MAX_BYTES];
+        let i = self.to_bytes_with_length(&mut output);
+        output[..i].to_vec()
+    }
+
+    /// Write bytes to a stream
+    pub fn to_writer<W: Write>(&self, writer: &mut W) -> Result<usize, Error> {
+        let mut output = [0u8; Self::MAX_BYTES];
+        let length = self.to_bytes_with_length(&mut output);
+        writer.write(&output[..length])
+    }
+
+    /// Read bytes from a stream
+    pub fn from_reader<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        let mut output = [0u8; Self::MAX_BYTES];
+        let mut i = 0;
+        while i < Self::MAX_BYTES {
+            reader.read_exact(&mut output[i..i + 1])?;
+            if Self::peek(&output[..=i]).is_some() {
+                return Self::try_from(&output[..=i])
+                    .map_err(|m| Error::new(ErrorKind::InvalidData, m));
+            }
+            i += 1;
+        }
+        Err(Error::new(ErrorKind::InvalidData, "invalid byte sequence"))
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn round_trip_boundaries() {
+    for n in [i128::MIN, -1, 0, i128::MAX] {
+        let i = Int(n);
+        let bytes = i.to_vec();
+        let back = Int::try_from(bytes.as_slice()).unwrap();
+        assert_eq!(back.0, n);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn small_negatives_are_compact() {
+    let i = Int(-1);
+    assert_eq!(i.to_vec().len(), 1);
+
+    let i = Int(1);
+    assert_eq!(i.to_vec().len(), 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn from_reader_round_trips_boundaries() {
+    for n in [i128::MIN, -1, 0, i128::MAX] {
+        let i = Int(n);
+        let bytes = i.to_vec();
+        let back = Int::from_reader(&mut bytes.as_slice()).unwrap();
+        assert_eq!(back.0, n);
+    }
+}
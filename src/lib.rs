@@ -5,6 +5,9 @@
 //!
 //! This also permits the user to not have to think about which value is the most efficient
 //! to compress.
+//!
+//! `Uint` stores unsigned values directly, while [`Int`] applies the zig-zag transform to
+//! signed values so small-magnitude negatives stay just as compact as small positives.
 #![no_std]
 #![deny(
     warnings,
@@ -24,7 +27,7 @@ use core::{
 use core2::io::{Error, ErrorKind, Read, Write};
 
 #[cfg(feature = "serde")]
-use serde::{
+use ::serde::{
     de::{Error as DError, Visitor},
     Deserialize, Deserializer, Serialize, Serializer,
 };
@@ -39,6 +42,22 @@ use alloc::{boxed::Box, vec::Vec};
 #[cfg(feature = "std")]
 use std::{boxed::Box, vec::Vec};
 
+mod int;
+pub use int::Int;
+
+mod seq;
+pub use seq::UintIter;
+
+mod compact_size;
+
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod packed;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+#[cfg(feature = "serde")]
+pub mod serde;
+
 /// Uint implements zig-zag encoding to represent integers as binary sequences
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Uint(pub u128);
@@ -484,16 +503,53 @@ impl Uint {
         let mut i = 0;
         while i < Self::MAX_BYTES {
             reader.read_exact(&mut output[i..i + 1])?;
-            if Self::peek(&output[..i]).is_some() {
-                break;
+            if Self::peek(&output[..=i]).is_some() {
+                return Self::try_from(&output[..=i])
+                    .map_err(|m| Error::new(ErrorKind::InvalidData, m));
             }
             i += 1;
         }
-        if i == Self::MAX_BYTES {
-            Err(Error::new(ErrorKind::InvalidData, "invalid byte sequence"))
-        } else {
-            Self::try_from(&output[..i]).map_err(|m| Error::new(ErrorKind::InvalidData, m))
+        Err(Error::new(ErrorKind::InvalidData, "invalid byte sequence"))
+    }
+
+    /// Decode a `Uint` from the start of `buf`, returning the value and the number of
+    /// bytes consumed.
+    ///
+    /// When at least `MAX_BYTES` bytes are available, this reads a fixed window up front
+    /// and peels 7-bit groups without re-checking `buf.len()` on every byte, unlike
+    /// `TryFrom<&[u8]>`. Near the end of a buffer it falls back to that same bounds-checked
+    /// path. This is the fast path used internally to decode a dense stream of varints.
+    ///
+    /// ```
+    /// use uint_zigzag::Uint;
+    ///
+    /// let buffer = [0x80u8, 0x01u8, 0xFFu8];
+    /// let (value, consumed) = Uint::from_bytes_fast(&buffer).unwrap();
+    ///
+    /// assert_eq!(value, Uint::from(128));
+    /// assert_eq!(consumed, 2);
+    /// ```
+    pub fn from_bytes_fast(buf: &[u8]) -> Result<(Self, usize), &'static str> {
+        if buf.len() < Self::MAX_BYTES {
+            let consumed = Self::peek(buf).ok_or("invalid byte sequence")?;
+            let value = Self::try_from(&buf[..consumed])?;
+            return Ok((value, consumed));
+        }
+
+        let mut window = [0u8; Self::MAX_BYTES];
+        window.copy_from_slice(&buf[..Self::MAX_BYTES]);
+
+        let mut x = 0u128;
+        let mut i = 0;
+        while i < Self::MAX_BYTES {
+            let b = window[i];
+            x |= ((b & 0x7f) as u128) << (7 * i);
+            if b < 0x80 {
+                return Ok((Self(x), i + 1));
+            }
+            i += 1;
         }
+        Err("invalid byte sequence")
     }
 }
 
@@ -504,3 +560,19 @@ fn max_bytes() {
     let bytes = u.to_vec();
     assert_eq!(bytes.len(), Uint::MAX_BYTES);
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn from_bytes_fast_matches_try_from() {
+    for value in [0u128, 1, 127, 128, 16384, u128::MAX] {
+        let u = Uint(value);
+        let mut padded = Vec::new();
+        padded.extend_from_slice(&u.to_vec());
+        padded.resize(Uint::MAX_BYTES + 4, 0xFF);
+
+        let (fast, consumed) = Uint::from_bytes_fast(&padded).unwrap();
+        assert_eq!(fast, Uint::try_from(padded.as_slice()).unwrap());
+        assert_eq!(fast, u);
+        assert_eq!(consumed, u.to_vec().len());
+    }
+}
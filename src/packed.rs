@@ -0,0 +1,178 @@
+//! Fixed-width bit-packed block format for arrays of `Uint`s.
+//!
+//! A per-element LEB128 byte still wastes bits when a slice holds many values of similar
+//! magnitude. This module instead writes a `Uint` element count and a single `bit_width`
+//! byte (the number of bits needed to hold the largest element), then packs every element
+//! back-to-back into exactly `bit_width` bits, giving near-optimal density for columns of
+//! similar-magnitude integers.
+use crate::Uint;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+struct BitWriter {
+    buf: Vec<u8>,
+    cur: u8,
+    cur_bits: u8,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        Self {
+            buf: Vec::new(),
+            cur: 0,
+            cur_bits: 0,
+        }
+    }
+
+    fn write_bits(&mut self, mut value: u128, mut width: u8) {
+        while width > 0 {
+            let take = (8 - self.cur_bits).min(width);
+            let mask = (1u128 << take) - 1;
+            self.cur |= ((value & mask) as u8) << self.cur_bits;
+            self.cur_bits += take;
+            value >>= take;
+            width -= take;
+
+            if self.cur_bits == 8 {
+                self.buf.push(self.cur);
+                self.cur = 0;
+                self.cur_bits = 0;
+            }
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.cur_bits > 0 {
+            self.buf.push(self.cur);
+        }
+        self.buf
+    }
+}
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    byte_idx: usize,
+    bit_idx: u8,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            byte_idx: 0,
+            bit_idx: 0,
+        }
+    }
+
+    fn read_bits(&mut self, mut width: u8) -> Result<u128, &'static str> {
+        let mut value: u128 = 0;
+        let mut shift = 0u8;
+
+        while width > 0 {
+            let byte = *self
+                .data
+                .get(self.byte_idx)
+                .ok_or("packed buffer ended before all elements were read")?;
+            let take = (8 - self.bit_idx).min(width);
+            let mask = ((1u16 << take) - 1) as u8;
+            let bits = (byte >> self.bit_idx) & mask;
+            value |= (bits as u128) << shift;
+
+            shift += take;
+            width -= take;
+            self.bit_idx += take;
+            if self.bit_idx == 8 {
+                self.bit_idx = 0;
+                self.byte_idx += 1;
+            }
+        }
+
+        Ok(value)
+    }
+}
+
+/// Bit-pack `items` into a count-prefixed, fixed-width block.
+///
+/// ```
+/// use uint_zigzag::{packed, Uint};
+///
+/// let items: Vec<Uint> = (0..64u32).map(|i| Uint::from(1000 + i)).collect();
+/// let packed = packed::pack_slice(&items);
+/// let naive: usize = items.iter().map(|u| u.to_vec().len()).sum();
+///
+/// assert!(packed.len() < naive);
+/// assert_eq!(packed::unpack_slice(&packed).unwrap(), items);
+/// ```
+pub fn pack_slice(items: &[Uint]) -> Vec<u8> {
+    let max_value = items.iter().map(|u| u.0).max().unwrap_or(0);
+    let bit_width = if max_value == 0 {
+        0
+    } else {
+        (128 - max_value.leading_zeros()) as u8
+    };
+
+    let mut count_buf = [0u8; Uint::MAX_BYTES];
+    let count_len = Uint::from(items.len()).to_bytes_with_length(&mut count_buf);
+
+    let mut out =
+        Vec::with_capacity(count_len + 1 + (items.len() * bit_width as usize).div_ceil(8));
+    out.extend_from_slice(&count_buf[..count_len]);
+    out.push(bit_width);
+
+    if bit_width > 0 {
+        let mut writer = BitWriter::new();
+        for item in items {
+            writer.write_bits(item.0, bit_width);
+        }
+        out.extend_from_slice(&writer.finish());
+    }
+
+    out
+}
+
+/// Unpack a block previously produced by [`pack_slice`].
+pub fn unpack_slice(data: &[u8]) -> Result<Vec<Uint>, &'static str> {
+    let count_len = Uint::peek(data).ok_or("invalid element count prefix")?;
+    let count = Uint::try_from(&data[..count_len])?.0 as usize;
+    let bit_width = *data.get(count_len).ok_or("missing bit width byte")?;
+
+    let mut reader = BitReader::new(&data[count_len + 1..]);
+    let mut out = Vec::with_capacity(count);
+    for _ in 0..count {
+        let value = if bit_width == 0 {
+            0
+        } else {
+            reader.read_bits(bit_width)?
+        };
+        out.push(Uint(value));
+    }
+    Ok(out)
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn pack_slice_beats_naive_encoding_for_similar_magnitude_values() {
+    let items: Vec<Uint> = (0..64u32).map(|i| Uint::from(1000 + i)).collect();
+    let packed = pack_slice(&items);
+    let naive: usize = items.iter().map(|u| u.to_vec().len()).sum();
+    assert!(packed.len() < naive);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn pack_slice_round_trips_zero_and_max_values() {
+    let items = std::vec![Uint::from(0u8), Uint::from(1u8), Uint(u128::MAX)];
+    let packed = pack_slice(&items);
+    assert_eq!(unpack_slice(&packed).unwrap(), items);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn pack_slice_round_trips_empty_slice() {
+    let items: Vec<Uint> = Vec::new();
+    let packed = pack_slice(&items);
+    assert_eq!(unpack_slice(&packed).unwrap(), items);
+}
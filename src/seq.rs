@@ -0,0 +1,143 @@
+//! Length-delimited sequence codec for streaming many `Uint`s, plus a zero-allocation
+//! borrowing iterator over back-to-back varints.
+use crate::Uint;
+use core2::io::{Error, Write};
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+use core2::io::Read;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+impl Uint {
+    /// Write a `Uint` count prefix followed by each of `items` encoded as a varint.
+    pub fn write_slice<W: Write>(items: &[Uint], w: &mut W) -> Result<usize, Error> {
+        let mut written = Uint::from(items.len()).to_writer(w)?;
+        for item in items {
+            written += item.to_writer(w)?;
+        }
+        Ok(written)
+    }
+
+    /// Read a `Uint` count prefix followed by that many varints.
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn read_vec<R: Read>(r: &mut R) -> Result<Vec<Uint>, Error> {
+        let count = Uint::from_reader(r)?.0 as usize;
+        let mut out = Vec::with_capacity(count);
+        for _ in 0..count {
+            out.push(Uint::from_reader(r)?);
+        }
+        Ok(out)
+    }
+}
+
+/// A zero-allocation, borrowing iterator over a byte slice of back-to-back `Uint` varints.
+///
+/// Unlike [`Uint::read_vec`], `UintIter` expects no count prefix; it walks `data` until
+/// exhausted, stopping and recording an error if the trailing bytes cannot be decoded as a
+/// complete `Uint`.
+///
+/// ```
+/// use uint_zigzag::{Uint, UintIter};
+///
+/// let mut buffer = Vec::new();
+/// Uint::from(1u8).to_writer(&mut buffer).unwrap();
+/// Uint::from(300u32).to_writer(&mut buffer).unwrap();
+///
+/// let values: Vec<_> = UintIter::new(&buffer).collect();
+/// assert_eq!(values, vec![Uint::from(1u8), Uint::from(300u32)]);
+/// ```
+pub struct UintIter<'a> {
+    remaining: &'a [u8],
+    error: bool,
+}
+
+impl<'a> UintIter<'a> {
+    /// Create a new iterator that borrows `data` and yields the `Uint`s encoded in it.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            remaining: data,
+            error: false,
+        }
+    }
+
+    /// Returns `true` if iteration stopped early because trailing bytes could not be
+    /// decoded as a complete `Uint`.
+    pub fn has_error(&self) -> bool {
+        self.error
+    }
+}
+
+impl<'a> Iterator for UintIter<'a> {
+    type Item = Uint;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() || self.error {
+            return None;
+        }
+
+        match Uint::from_bytes_fast(self.remaining) {
+            Ok((value, consumed)) => {
+                self.remaining = &self.remaining[consumed..];
+                Some(value)
+            }
+            Err(_) => {
+                self.error = true;
+                None
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn write_slice_then_read_vec_round_trips() {
+    let items = std::vec![
+        Uint::from(0u8),
+        Uint::from(127u8),
+        Uint::from(128u32),
+        Uint::from(u128::MAX)
+    ];
+
+    let mut buffer = Vec::new();
+    Uint::write_slice(&items, &mut buffer).unwrap();
+
+    let mut cursor = buffer.as_slice();
+    let back = Uint::read_vec(&mut cursor).unwrap();
+    assert_eq!(back, items);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn uint_iter_walks_back_to_back_varints() {
+    let items = std::vec![Uint::from(1u8), Uint::from(300u32), Uint::from(u128::MAX)];
+
+    let mut buffer = Vec::new();
+    for item in &items {
+        item.to_writer(&mut buffer).unwrap();
+    }
+
+    let decoded: Vec<_> = UintIter::new(&buffer).collect();
+    assert_eq!(decoded, items);
+
+    let mut iter = UintIter::new(&buffer);
+    assert!(!iter.has_error());
+    let _ = iter.by_ref().count();
+    assert!(!iter.has_error());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn uint_iter_flags_trailing_garbage() {
+    let mut buffer = Vec::new();
+    Uint::from(1u8).to_writer(&mut buffer).unwrap();
+    buffer.push(0x80);
+
+    let mut iter = UintIter::new(&buffer);
+    assert_eq!(iter.next(), Some(Uint::from(1u8)));
+    assert_eq!(iter.next(), None);
+    assert!(iter.has_error());
+}
@@ -0,0 +1,262 @@
+//! Alternative serde representations for [`Uint`], selected with `#[serde(with = "...")]`.
+//!
+//! The `Serialize`/`Deserialize` impls on `Uint` itself always emit raw varint
+//! bytes, which is compact but opaque in human-readable formats like JSON or TOML. These
+//! submodules provide free `serialize`/`deserialize` functions for the common
+//! human-readable alternatives:
+//!
+//! - [`decimal`]: a decimal string, e.g. `"345678"`.
+//! - [`prefixed`]: a `0x`-prefixed hex string on output, accepting either hex or decimal
+//!   strings on input, e.g. `"0x5464e"`.
+//! - [`bytes::be`] / [`bytes::le`]: a fixed 16-byte big/little-endian array.
+use crate::Uint;
+use core::fmt::{self, Formatter, Write as _};
+use ::serde::{
+    de::{Error as DError, SeqAccess, Unexpected, Visitor},
+    Deserializer, Serializer,
+};
+
+struct FixedWriter {
+    buf: [u8; 40],
+    len: usize,
+}
+
+impl FixedWriter {
+    fn new() -> Self {
+        Self {
+            buf: [0u8; 40],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or("")
+    }
+}
+
+impl fmt::Write for FixedWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let bytes = s.as_bytes();
+        if self.len + bytes.len() > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+        Ok(())
+    }
+}
+
+/// Represent a `Uint` as a decimal string, e.g. `"345678"`.
+///
+/// ```
+/// # #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Wrapper(#[serde(with = "uint_zigzag::serde::decimal")] uint_zigzag::Uint);
+/// ```
+pub mod decimal {
+    use super::*;
+
+    /// Serialize a `Uint` as a decimal string.
+    pub fn serialize<S: Serializer>(value: &Uint, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut w = FixedWriter::new();
+        write!(w, "{}", value.0).map_err(|_| ::serde::ser::Error::custom("failed to format Uint"))?;
+        serializer.serialize_str(w.as_str())
+    }
+
+    /// Deserialize a `Uint` from a decimal string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uint, D::Error> {
+        struct DecimalVisitor;
+
+        impl<'de> Visitor<'de> for DecimalVisitor {
+            type Value = Uint;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "a decimal integer string")
+            }
+
+            fn visit_str<E: DError>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse::<u128>()
+                    .map(Uint)
+                    .map_err(|_| DError::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_str(DecimalVisitor)
+    }
+}
+
+/// Represent a `Uint` as a `0x`-prefixed hex string, e.g. `"0x5464e"`, accepting either a
+/// hex or a plain decimal string on input.
+pub mod prefixed {
+    use super::*;
+
+    /// Serialize a `Uint` as a `0x`-prefixed hex string.
+    pub fn serialize<S: Serializer>(value: &Uint, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut w = FixedWriter::new();
+        write!(w, "0x{:x}", value.0)
+            .map_err(|_| ::serde::ser::Error::custom("failed to format Uint"))?;
+        serializer.serialize_str(w.as_str())
+    }
+
+    /// Deserialize a `Uint` from a `0x`-prefixed hex string or a plain decimal string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uint, D::Error> {
+        struct PrefixedVisitor;
+
+        impl<'de> Visitor<'de> for PrefixedVisitor {
+            type Value = Uint;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "a 0x-prefixed hex string or a decimal integer string")
+            }
+
+            fn visit_str<E: DError>(self, v: &str) -> Result<Self::Value, E> {
+                let parsed = match v.strip_prefix("0x").or_else(|| v.strip_prefix("0X")) {
+                    Some(hex) => u128::from_str_radix(hex, 16),
+                    None => v.parse::<u128>(),
+                };
+                parsed
+                    .map(Uint)
+                    .map_err(|_| DError::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_str(PrefixedVisitor)
+    }
+}
+
+/// Represent a `Uint` as a fixed 16-byte array.
+pub mod bytes {
+    /// Big-endian fixed 16-byte representation.
+    pub mod be {
+        use super::super::*;
+
+        /// Serialize a `Uint` as a 16-byte big-endian array.
+        pub fn serialize<S: Serializer>(value: &Uint, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(&value.0.to_be_bytes())
+        }
+
+        /// Deserialize a `Uint` from a 16-byte big-endian array.
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uint, D::Error> {
+            struct BeVisitor;
+
+            impl<'de> Visitor<'de> for BeVisitor {
+                type Value = Uint;
+
+                fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    write!(f, "a 16-byte big-endian array")
+                }
+
+                fn visit_bytes<E: DError>(self, v: &[u8]) -> Result<Self::Value, E> {
+                    let arr: [u8; 16] =
+                        v.try_into().map_err(|_| DError::invalid_length(v.len(), &self))?;
+                    Ok(Uint(u128::from_be_bytes(arr)))
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    let mut arr = [0u8; 16];
+                    for (i, byte) in arr.iter_mut().enumerate() {
+                        *byte = seq
+                            .next_element()?
+                            .ok_or_else(|| DError::invalid_length(i, &self))?;
+                    }
+                    Ok(Uint(u128::from_be_bytes(arr)))
+                }
+            }
+
+            deserializer.deserialize_bytes(BeVisitor)
+        }
+    }
+
+    /// Little-endian fixed 16-byte representation.
+    pub mod le {
+        use super::super::*;
+
+        /// Serialize a `Uint` as a 16-byte little-endian array.
+        pub fn serialize<S: Serializer>(value: &Uint, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(&value.0.to_le_bytes())
+        }
+
+        /// Deserialize a `Uint` from a 16-byte little-endian array.
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uint, D::Error> {
+            struct LeVisitor;
+
+            impl<'de> Visitor<'de> for LeVisitor {
+                type Value = Uint;
+
+                fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    write!(f, "a 16-byte little-endian array")
+                }
+
+                fn visit_bytes<E: DError>(self, v: &[u8]) -> Result<Self::Value, E> {
+                    let arr: [u8; 16] =
+                        v.try_into().map_err(|_| DError::invalid_length(v.len(), &self))?;
+                    Ok(Uint(u128::from_le_bytes(arr)))
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    let mut arr = [0u8; 16];
+                    for (i, byte) in arr.iter_mut().enumerate() {
+                        *byte = seq
+                            .next_element()?
+                            .ok_or_else(|| DError::invalid_length(i, &self))?;
+                    }
+                    Ok(Uint(u128::from_le_bytes(arr)))
+                }
+            }
+
+            deserializer.deserialize_bytes(LeVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn decimal_round_trips() {
+    #[derive(::serde::Serialize, ::serde::Deserialize, Debug, PartialEq)]
+    struct Wrapper(#[serde(with = "crate::serde::decimal")] Uint);
+
+    let w = Wrapper(Uint::from(345678u32));
+    let json = serde_json::to_string(&w).unwrap();
+    assert_eq!(json, "\"345678\"");
+    assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), w);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn prefixed_accepts_hex_and_decimal() {
+    #[derive(::serde::Serialize, ::serde::Deserialize, Debug, PartialEq)]
+    struct Wrapper(#[serde(with = "crate::serde::prefixed")] Uint);
+
+    let w = Wrapper(Uint::from(345678u32));
+    let json = serde_json::to_string(&w).unwrap();
+    assert_eq!(json, "\"0x5464e\"");
+    assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), w);
+    assert_eq!(
+        serde_json::from_str::<Wrapper>("\"345678\"").unwrap(),
+        w
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn bytes_round_trip_be_and_le() {
+    #[derive(::serde::Serialize, ::serde::Deserialize, Debug, PartialEq)]
+    struct BeWrapper(#[serde(with = "crate::serde::bytes::be")] Uint);
+    #[derive(::serde::Serialize, ::serde::Deserialize, Debug, PartialEq)]
+    struct LeWrapper(#[serde(with = "crate::serde::bytes::le")] Uint);
+
+    let value = Uint::from(u128::MAX / 3);
+
+    let be = BeWrapper(value);
+    let json = serde_json::to_string(&be).unwrap();
+    assert_eq!(serde_json::from_str::<BeWrapper>(&json).unwrap(), be);
+
+    let le = LeWrapper(value);
+    let json = serde_json::to_string(&le).unwrap();
+    assert_eq!(serde_json::from_str::<LeWrapper>(&json).unwrap(), le);
+}